@@ -0,0 +1,23 @@
+use bevy::{
+    app::{App, Plugin, Update},
+    ecs::event::EventWriter,
+    input::{keyboard::KeyCode, ButtonInput},
+    prelude::Res,
+};
+
+use crate::GreetEvent;
+
+/// Lets players trigger a greeting on demand instead of waiting for the timer.
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, greet_on_keypress);
+    }
+}
+
+fn greet_on_keypress(keys: Res<ButtonInput<KeyCode>>, mut greet_events: EventWriter<GreetEvent>) {
+    if keys.just_pressed(KeyCode::Space) {
+        greet_events.send(GreetEvent);
+    }
+}
@@ -1,51 +1,147 @@
 use bevy::{
-    app::{App, Plugin, Startup, Update},
-    prelude::{Commands, Component, IntoSystemConfigs, Query, Res, ResMut, Resource, With},
+    app::{App, Plugin, PluginGroup, PluginGroupBuilder, Startup, Update},
+    ecs::event::{Event, EventReader, EventWriter},
+    prelude::{Commands, Component, Entity, IntoSystemConfigs, Query, Res, ResMut, Resource, With},
     time::{Time, Timer, TimerMode},
     DefaultPlugins,
 };
 
+mod input_plugin;
+mod mesh_plugin;
+
+use input_plugin::InputPlugin;
+use mesh_plugin::MeshPlugin;
+
 #[derive(Component)]
-struct Person;
+pub(crate) struct Person;
 
 #[derive(Component)]
-struct Name(String);
+pub(crate) struct Name(pub(crate) String);
+
+/// The people an entity considers friends, greeted alongside them by
+/// [`greet_friends`].
+#[derive(Component)]
+pub(crate) struct Friends(pub(crate) Vec<Entity>);
 
 #[derive(Resource)]
 struct GreetTimer(Timer);
+
+/// Fired whenever people should be greeted, regardless of what triggered it
+/// (the repeating [`GreetTimer`] or a keypress handled by [`InputPlugin`]).
+#[derive(Event)]
+pub(crate) struct GreetEvent;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(HelloPlugin)
+        .add_plugins(HelloPluginGroup)
         .run();
 }
 
-pub struct HelloPlugin;
+/// Bundles [`HelloPlugin`] with the visual and input plugins that make the
+/// tutorial app watchable and interactive, mirroring how `DefaultPlugins`
+/// groups Bevy's own subsystems.
+pub struct HelloPluginGroup;
+
+impl PluginGroup for HelloPluginGroup {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(HelloPlugin::default())
+            .add(MeshPlugin)
+            .add(InputPlugin)
+    }
+}
+
+/// Knobs for [`HelloPlugin`]: how often to greet, and what to say.
+#[derive(Resource, Clone)]
+pub struct HelloPluginSettings {
+    pub interval_secs: f32,
+    pub greeting: String,
+}
+
+impl Default for HelloPluginSettings {
+    fn default() -> Self {
+        Self {
+            interval_secs: 2.0,
+            greeting: "Hello {}!".to_string(),
+        }
+    }
+}
+
+pub struct HelloPlugin {
+    pub settings: HelloPluginSettings,
+}
+
+impl Default for HelloPlugin {
+    fn default() -> Self {
+        Self {
+            settings: HelloPluginSettings::default(),
+        }
+    }
+}
 
 impl Plugin for HelloPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(GreetTimer(Timer::from_seconds(2.0, TimerMode::Repeating)));
+        app.insert_resource(GreetTimer(Timer::from_seconds(
+            self.settings.interval_secs,
+            TimerMode::Repeating,
+        )));
+        app.insert_resource(self.settings.clone());
+        app.add_event::<GreetEvent>();
         app.add_systems(Startup, add_people);
-        app.add_systems(Update, (update_people, greet_people).chain());
+        app.add_systems(
+            Update,
+            (update_people, tick_greet_timer, greet_people, greet_friends).chain(),
+        );
     }
 }
 
 fn add_people(mut commands: Commands) {
-    commands.spawn((Person, Name("Ziomek".to_string())));
-    commands.spawn((Person, Name("Mateusz".to_string())));
-    commands.spawn((Person, Name("Adam".to_string())));
+    let ziomek = commands.spawn((Person, Name("Ziomek".to_string()))).id();
+    let mateusz = commands.spawn((Person, Name("Mateusz".to_string()))).id();
+    let adam = commands.spawn((Person, Name("Adam".to_string()))).id();
+
+    commands.entity(ziomek).insert(Friends(vec![mateusz]));
+    commands.entity(mateusz).insert(Friends(vec![ziomek, adam]));
+    commands.entity(adam).insert(Friends(vec![mateusz]));
 }
 
-fn greet_people(
+fn tick_greet_timer(
     time: Res<Time>,
     mut greet_timer: ResMut<GreetTimer>,
-    query: Query<&Name, With<Person>>,
+    mut greet_events: EventWriter<GreetEvent>,
 ) {
     // Update timer with the time elapsed since last update.
     // if that caused the timer to finish, we say hello
     if greet_timer.0.tick(time.delta()).just_finished() {
+        greet_events.send(GreetEvent);
+    }
+}
+
+fn greet_people(
+    mut greet_events: EventReader<GreetEvent>,
+    settings: Res<HelloPluginSettings>,
+    query: Query<&Name, With<Person>>,
+) {
+    for _ in greet_events.read() {
         for name in &query {
-            println!("Hello {}!", name.0);
+            println!("{}", settings.greeting.replace("{}", &name.0));
+        }
+    }
+}
+
+fn greet_friends(
+    mut greet_events: EventReader<GreetEvent>,
+    friends_query: Query<(&Name, &Friends)>,
+    names_query: Query<&Name>,
+) {
+    for _ in greet_events.read() {
+        for (name, friends) in &friends_query {
+            for &friend in &friends.0 {
+                // The friend may have been despawned; skip it rather than panicking.
+                if let Ok(friend_name) = names_query.get(friend) {
+                    println!("{} waves at {}!", name.0, friend_name.0);
+                }
+            }
         }
     }
 }
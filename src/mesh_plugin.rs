@@ -0,0 +1,49 @@
+use bevy::{
+    app::{App, Plugin, Startup, Update},
+    prelude::{
+        default, BuildChildren, Camera2dBundle, Color, Commands, Entity, Query, Sprite,
+        SpriteBundle, Text, Text2dBundle, TextStyle, Transform, With, Without,
+    },
+};
+
+use crate::{Name, Person};
+
+/// Draws every `Person` as a sprite with their name floating above it.
+///
+/// Greeting logic stays in [`crate::HelloPlugin`]; this plugin only owns presentation.
+pub struct MeshPlugin;
+
+impl Plugin for MeshPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_camera);
+        app.add_systems(Update, spawn_person_visuals);
+    }
+}
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn spawn_person_visuals(
+    mut commands: Commands,
+    query: Query<(Entity, &Name), (With<Person>, Without<Sprite>)>,
+) {
+    for (entity, name) in &query {
+        commands.entity(entity).insert(SpriteBundle {
+            sprite: Sprite {
+                color: Color::WHITE,
+                custom_size: Some((32.0, 32.0).into()),
+                ..default()
+            },
+            ..default()
+        });
+
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn(Text2dBundle {
+                text: Text::from_section(name.0.clone(), TextStyle::default()),
+                transform: Transform::from_xyz(0.0, 24.0, 1.0),
+                ..default()
+            });
+        });
+    }
+}